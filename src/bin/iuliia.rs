@@ -0,0 +1,54 @@
+extern crate iuliia_rust;
+
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let mut schema_name = String::from("wikipedia");
+    let mut list = false;
+    let mut inputs: Vec<String> = Vec::new();
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--schema" => {
+                i += 1;
+                match args.get(i) {
+                    Some(value) => schema_name = value.clone(),
+                    None => {
+                        eprintln!("--schema requires a value");
+                        process::exit(1);
+                    }
+                }
+            }
+            "--list" => list = true,
+            other => inputs.push(other.to_string()),
+        }
+        i += 1;
+    }
+
+    if list {
+        for (name, description) in iuliia_rust::list_schemas() {
+            println!("{}\t{}", name, description);
+        }
+        return;
+    }
+
+    if inputs.is_empty() {
+        let stdin = io::stdin();
+        let stdout = io::stdout();
+        let mut out = stdout.lock();
+        for line in stdin.lock().lines() {
+            let line = line.expect("failed to read line from stdin");
+            writeln!(out, "{}", iuliia_rust::parse_by_schema_name(&line, &schema_name))
+                .expect("failed to write output");
+        }
+    } else {
+        for input in &inputs {
+            println!("{}", iuliia_rust::parse_by_schema_name(input, &schema_name));
+        }
+    }
+}
@@ -2,15 +2,58 @@
 extern crate include_dir;
 extern crate regex;
 
+mod trie;
+
 use include_dir::Dir;
 use regex::Regex;
 use lazy_static::lazy_static;
+use trie::Trie;
 
 use std::collections::HashMap;
+use std::fmt;
+use std::io::Read;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 use serde::{Serialize, Deserialize};
 
 const SCHEMA_DIR: Dir = include_dir!("./iuliia");
-const DUMMY_SYMBOL: &str = "$";
+
+lazy_static! {
+    static ref SCHEMA_CACHE: Mutex<HashMap<String, Arc<Schema>>> = Mutex::new(HashMap::new());
+}
+
+/// Error returned when a `Schema` cannot be looked up or parsed
+#[derive(Debug)]
+pub enum SchemaError {
+    /// No schema with this name is compiled into `SCHEMA_DIR`
+    NotFound(String),
+    /// The schema JSON could not be deserialized
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SchemaError::NotFound(name) => write!(f, "There are no schema with name {}", name),
+            SchemaError::Parse(err) => write!(f, "failed to parse schema: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SchemaError::NotFound(_) => None,
+            SchemaError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<serde_json::Error> for SchemaError {
+    fn from(err: serde_json::Error) -> Self {
+        SchemaError::Parse(err)
+    }
+}
 
 /// Describe struct of transliterate schema
 #[derive(Serialize, Deserialize, Debug)]
@@ -23,57 +66,231 @@ pub struct Schema {
     next_mapping: Option<HashMap<String, String>>,
     ending_mapping: Option<HashMap<String, String>>,
     samples: Option<Vec<Vec<String>>>,
+    #[serde(skip)]
+    automaton: ContextAutomaton,
 }
 
-impl Schema {
-    /// Return Schema object by schema name
-    pub fn for_name(s: &str) -> Schema {
-        let schema_file = SCHEMA_DIR.get_file(format!("{}{}", s, ".json"))
-            .expect(&format!("There are no schema with name {}", s));
-        serde_json::from_str(schema_file.contents_utf8().unwrap()).unwrap()
-    }
+/// Compiled matcher for a `Schema`'s mappings, built once when the schema is
+/// loaded instead of being re-derived from `HashMap`s on every letter.
+///
+/// `mapping` and `next_mapping` are kept as separate tries, each matched
+/// forward from the cursor, since a single-char `next_mapping` key means
+/// "this letter at word end" rather than a context-free letter rule (see
+/// `parse_letter`'s word-end guard). `prev_mapping`/`ending_mapping` keys
+/// are matched backward (from the cursor and from the end of the word,
+/// respectively). The context tries match by longest key and the ending trie
+/// by shortest key (see `parse_ending`), so context is no longer limited to a
+/// single neighboring letter.
+#[derive(Debug, Default)]
+struct ContextAutomaton {
+    forward: Trie,
+    next: Trie,
+    backward: Trie,
+    ending: Trie,
+    reverse: Trie,
+    reversibility: Reversibility,
+}
 
-    pub fn get_pref(&self, s: &str) -> Option<String> {
-        if self.prev_mapping.is_none() {
-            return None;
+impl ContextAutomaton {
+    fn compile(schema: &Schema) -> ContextAutomaton {
+        let mut forward = Trie::new();
+        if let Some(mapping) = &schema.mapping {
+            for (key, value) in mapping {
+                forward.insert(key, value.clone());
+            }
         }
-        match self.prev_mapping.as_ref().unwrap().get(&s.replace(DUMMY_SYMBOL.clone(), "").to_lowercase()) {
-            Some(result) => Some(result.clone()),
-            None => None
+
+        let mut next = Trie::new();
+        if let Some(next_mapping) = &schema.next_mapping {
+            for (key, value) in next_mapping {
+                next.insert(key, value.clone());
+            }
         }
-    }
 
-    pub fn get_next(&self, s: &str) -> Option<String> {
-        if self.next_mapping.is_none() {
-            return None;
+        let mut backward = Trie::new();
+        if let Some(prev_mapping) = &schema.prev_mapping {
+            for (key, value) in prev_mapping {
+                backward.insert(&reversed(key), value.clone());
+            }
         }
-        match self.next_mapping.as_ref().unwrap().get(&s.replace(DUMMY_SYMBOL.clone(), "").to_lowercase()) {
-            Some(result) => Some(result.clone()),
-            None => None
+
+        let mut ending = Trie::new();
+        if let Some(ending_mapping) = &schema.ending_mapping {
+            for (key, value) in ending_mapping {
+                ending.insert(&reversed(key), value.clone());
+            }
         }
+
+        let (reverse, reversibility) = compile_reverse(schema);
+
+        ContextAutomaton { forward, next, backward, ending, reverse, reversibility }
     }
+}
+
+fn reversed(s: &str) -> String {
+    s.chars().rev().collect()
+}
 
-    pub fn get_letter(&self, s: &str) -> Option<String> {
-        if self.mapping.is_none() {
-            return None;
+/// Whether round-tripping a schema's Latin output back through
+/// [`detransliterate_by_schema`] is guaranteed to recover the original Cyrillic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reversibility {
+    /// Every Latin sequence produced by this schema maps back to exactly one Cyrillic source
+    Reversible,
+    /// Some Latin sequences are ambiguous (e.g. `e` from both `е` and `э`); sources from
+    /// `mapping` are preferred over context rules, which are preferred over endings, and
+    /// among the preferred ones the source with the most candidate rules mapping to it is
+    /// chosen (ties broken lexicographically), so round-tripping may not recover the original text
+    BestEffort,
+}
+
+impl Default for Reversibility {
+    fn default() -> Reversibility {
+        Reversibility::Reversible
+    }
+}
+
+/// Build the Latin-to-Cyrillic inverse of a schema's mappings.
+///
+/// The forward mapping is many-to-one (several Cyrillic letters can romanize
+/// to the same Latin sequence), so inverting it is inherently lossy. For each
+/// Latin sequence, candidate Cyrillic sources are drawn from `mapping` first
+/// (context-free letters take priority), falling back to the current letter
+/// of `prev_mapping`/`next_mapping` entries and to whole `ending_mapping`
+/// suffixes. Within the highest-priority tier a Latin sequence has candidates
+/// in, the source with the most candidate rules mapping to it wins, ties
+/// broken lexicographically for determinism. Any Latin sequence with more
+/// than one distinct candidate makes the schema best-effort.
+fn compile_reverse(schema: &Schema) -> (Trie, Reversibility) {
+    let mut candidates: HashMap<String, Vec<(String, u8)>> = HashMap::new();
+    let mut push = |latin: &str, cyrillic: String, tier: u8| {
+        candidates.entry(latin.to_string()).or_default().push((cyrillic, tier));
+    };
+
+    if let Some(mapping) = &schema.mapping {
+        for (key, value) in mapping {
+            push(value, key.clone(), 0);
+        }
+    }
+    if let Some(prev_mapping) = &schema.prev_mapping {
+        for (key, value) in prev_mapping {
+            if let Some(current) = key.chars().last() {
+                push(value, current.to_string(), 1);
+            }
+        }
+    }
+    if let Some(next_mapping) = &schema.next_mapping {
+        for (key, value) in next_mapping {
+            if let Some(current) = key.chars().next() {
+                push(value, current.to_string(), 1);
+            }
         }
-        match self.mapping.as_ref().unwrap().get(&s.to_lowercase()) {
-            Some(result) => Some(result.clone()),
-            None => None
+    }
+    if let Some(ending_mapping) = &schema.ending_mapping {
+        for (key, value) in ending_mapping {
+            push(value, key.clone(), 2);
         }
     }
 
-    pub fn get_ending(&self, s: &str) -> Option<String> {
-        if self.ending_mapping.is_none() {
-            return None;
+    let mut reverse = Trie::new();
+    let mut reversibility = Reversibility::Reversible;
+
+    for (latin, sources) in candidates {
+        let mut distinct: Vec<&String> = sources.iter().map(|(cyrillic, _)| cyrillic).collect();
+        distinct.sort();
+        distinct.dedup();
+        if distinct.len() > 1 {
+            reversibility = Reversibility::BestEffort;
+        }
+
+        let best_tier = sources.iter().map(|(_, tier)| *tier).min().unwrap();
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for (cyrillic, tier) in &sources {
+            if *tier == best_tier {
+                *counts.entry(cyrillic.as_str()).or_default() += 1;
+            }
         }
-        match self.ending_mapping.as_ref().unwrap().get(&s.to_lowercase()) {
-            Some(result) => Some(result.clone()),
-            None => None
+
+        let mut ranked: Vec<(&str, usize)> = counts.into_iter().collect();
+        ranked.sort_by(|(a, count_a), (b, count_b)| count_b.cmp(count_a).then(a.cmp(b)));
+        if let Some((cyrillic, _)) = ranked.into_iter().next() {
+            reverse.insert(&latin, cyrillic.to_string());
+        }
+    }
+
+    (reverse, reversibility)
+}
+
+impl Schema {
+    /// Return a shared, cached `Schema` by name, parsing it from `SCHEMA_DIR` only once
+    ///
+    /// Panics if no schema with this name is compiled in or its JSON is malformed.
+    /// Use [`Schema::try_for_name`] to handle either case instead.
+    pub fn for_name(s: &str) -> Arc<Schema> {
+        Schema::try_for_name(s).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Return a shared, cached `Schema` by name without panicking on a missing or malformed schema
+    pub fn try_for_name(s: &str) -> Result<Arc<Schema>, SchemaError> {
+        let mut cache = SCHEMA_CACHE.lock().unwrap();
+        if let Some(schema) = cache.get(s) {
+            return Ok(schema.clone());
         }
+
+        let schema_file = SCHEMA_DIR.get_file(format!("{}{}", s, ".json"))
+            .ok_or_else(|| SchemaError::NotFound(s.to_string()))?;
+        let schema = Schema::from_str(schema_file.contents_utf8().unwrap())?;
+        let schema = Arc::new(schema);
+        cache.insert(s.to_string(), schema.clone());
+        Ok(schema)
+    }
+
+    /// Parse a `Schema` from JSON read off any `Read` source, e.g. a file on disk
+    pub fn from_reader<R: Read>(reader: R) -> Result<Schema, SchemaError> {
+        let mut schema: Schema = serde_json::from_reader(reader)?;
+        schema.automaton = ContextAutomaton::compile(&schema);
+        Ok(schema)
+    }
+
+    /// List the names of every schema compiled in from `SCHEMA_DIR`
+    pub fn available() -> Vec<&'static str> {
+        SCHEMA_DIR.files().iter()
+            .map(|file| file.path().file_stem().unwrap().to_str().unwrap())
+            .collect()
+    }
+
+    /// Reference conformance samples shipped with this schema, each row being `[source, expected, ...]`
+    pub fn samples(&self) -> Option<&[Vec<String>]> {
+        self.samples.as_deref()
+    }
+
+    /// Whether [`detransliterate_by_schema`] can perfectly round-trip this schema's output
+    pub fn reversibility(&self) -> Reversibility {
+        self.automaton.reversibility
     }
 }
 
+impl FromStr for Schema {
+    type Err = SchemaError;
+
+    /// Parse a `Schema` from a JSON string, e.g. a custom rule set not shipped in `SCHEMA_DIR`
+    fn from_str(s: &str) -> Result<Schema, SchemaError> {
+        let mut schema: Schema = serde_json::from_str(s)?;
+        schema.automaton = ContextAutomaton::compile(&schema);
+        Ok(schema)
+    }
+}
+
+/// List the `(name, description)` of every schema embedded in `SCHEMA_DIR`
+pub fn list_schemas() -> Vec<(String, String)> {
+    SCHEMA_DIR.files().iter()
+        .map(|file| {
+            let schema: Schema = serde_json::from_str(file.contents_utf8().unwrap()).unwrap();
+            (schema.name, schema.description)
+        })
+        .collect()
+}
+
 /// Transliterate a slice of str using name of schema to `String`
 ///
 /// ```
@@ -93,107 +310,177 @@ pub fn parse_by_schema_name(s: &str, schema_name: &str) -> String {
 /// let input = "Юлия, съешь ещё этих мягких французских булок из Йошкар-Олы, да выпей алтайского чаю";
 /// let expected = "Yuliya, syesh yeshchyo etikh myagkikh frantsuzskikh bulok iz Yoshkar-Oly, da vypey altayskogo chayu";
 /// let schema = iuliia_rust::Schema::for_name("wikipedia");
-/// 
+///
 /// let transliterated_word = iuliia_rust::parse_by_schema(&input, &schema);
 ///
 /// assert_eq!(transliterated_word, expected)
 /// ```
 ///
 pub fn parse_by_schema(s: &str, schema: &Schema) -> String {
+    parse_by_schema_with_casing(s, schema, CasingMode::Auto)
+}
+
+/// How the case of a source letter (or word) is reflected in its transliteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CasingMode {
+    /// Upper-case the whole replacement whenever the source letter is upper-case,
+    /// even when the replacement is itself several Latin letters, e.g. `Щ` -> `SHCH`.
+    AsSource,
+    /// Upper-case only the first letter of the replacement, e.g. `Щ` -> `Shch`.
+    /// Keeps mixed-case words like `ноГа` -> `noGa` intact.
+    TitleFirst,
+    /// [`TitleFirst`](CasingMode::TitleFirst) for most words, but a whole word written
+    /// in all caps (e.g. `ВЕЛИКИЙ`) is rendered in all caps too (`VELIKY`) rather than
+    /// title-cased (`Veliky`). This is what [`parse_by_schema`] uses.
+    Auto,
+}
+
+/// Transliterate a slice of str using `Schema` to `String`, forcing a specific [`CasingMode`]
+/// instead of the word-level heuristic [`parse_by_schema`] applies.
+pub fn parse_by_schema_with_casing(s: &str, schema: &Schema, mode: CasingMode) -> String {
     lazy_static! {
         static ref RE: Regex = Regex::new(r"\b").unwrap();
     }
     RE.split(s)
-        .map(|word| parse_word_by_schema(word, schema))
+        .map(|word| parse_word_by_schema(word, schema, mode))
         .collect()
 }
 
-fn parse_word_by_schema(s: &str, schema: &Schema) -> String {
-    let word_by_letters: Vec<String> = s.chars()
-        .map(|char| char.to_string())
-        .collect::<Vec<_>>();
-
-    //Parse ending
-    let ending = parse_ending(&word_by_letters, schema);
-    let mut parsed_end = String::new();
-    let word_without_ending = match ending {
-        Some(matched) => {
-            parsed_end = matched.translate;
-            word_by_letters[..matched.ending_start].to_vec()
+/// Transliterate a slice of str using name of schema to `String`, forcing a specific
+/// [`CasingMode`] instead of the word-level heuristic [`parse_by_schema_name`] applies.
+pub fn parse_by_schema_name_with_casing(s: &str, schema_name: &str, mode: CasingMode) -> String {
+    let schema = Schema::for_name(schema_name);
+    parse_by_schema_with_casing(s, &schema, mode)
+}
+
+/// Recover Cyrillic from Latin text produced by a named schema
+///
+/// See [`detransliterate_by_schema`] for how ambiguous Latin sequences are resolved;
+/// check [`Schema::reversibility`] to know whether the round-trip is exact for this schema.
+pub fn detransliterate_by_schema_name(s: &str, schema_name: &str) -> String {
+    let schema = Schema::for_name(schema_name);
+    detransliterate_by_schema(s, &schema)
+}
+
+/// Recover Cyrillic from Latin text produced by `schema`, greedily consuming the
+/// longest Latin sequence known to this schema's inverse at each position and
+/// copying through anything the schema never produces.
+///
+/// This is inherently lossy for schemas whose [`Schema::reversibility`] is
+/// [`Reversibility::BestEffort`]: several Cyrillic letters can romanize to the
+/// same Latin sequence, so only the most common source is recovered.
+pub fn detransliterate_by_schema(s: &str, schema: &Schema) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let lower: Vec<char> = chars.iter().map(|c| c.to_lowercase().next().unwrap()).collect();
+
+    let mut result = String::new();
+    let mut cursor = 0;
+    while cursor < chars.len() {
+        match schema.automaton.reverse.longest_match(&lower, cursor) {
+            Some((cyrillic, length)) => {
+                let source: String = chars[cursor..cursor + length].iter().collect();
+                result.push_str(&propagate_case_from_source(cyrillic.to_string(), &source, CasingMode::AsSource));
+                cursor += length;
+            }
+            None => {
+                result.push(chars[cursor]);
+                cursor += 1;
+            }
         }
-        None => word_by_letters
+    }
+    result
+}
+
+fn parse_word_by_schema(s: &str, schema: &Schema, mode: CasingMode) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let lower: Vec<char> = chars.iter().map(|c| c.to_lowercase().next().unwrap()).collect();
+
+    let mode = match mode {
+        CasingMode::Auto if is_all_uppercase(&chars) => CasingMode::AsSource,
+        CasingMode::Auto => CasingMode::TitleFirst,
+        explicit => explicit,
+    };
+
+    //Parse ending, then transliterate what's left letter by letter
+    let (word_len, parsed_end) = match parse_ending(&chars, &lower, schema, mode) {
+        Some((ending_start, translate)) => (ending_start, translate),
+        None => (chars.len(), String::new())
     };
 
-    //Add dummy symbols for window function
-    let mut word_for_parse: Vec<String> = Vec::with_capacity(word_without_ending.len() + 2);
-    let dummy_string: Vec<String> = vec![String::from(DUMMY_SYMBOL.clone())];
-    word_for_parse.extend(dummy_string.clone());
-    word_for_parse.extend(word_without_ending);
-    word_for_parse.extend(dummy_string);
-
-    //Parse each letter
-    let parsed_word: String = word_for_parse
-        .windows(3)
-        .map(|letter_with_neighbors| parse_letter(letter_with_neighbors, schema))
+    let parsed_word: String = (0..word_len)
+        .map(|cursor| parse_letter(&chars, &lower, cursor, schema, mode))
         .collect();
 
-    //Concat with ending
     format!("{}{}", parsed_word, parsed_end)
 }
 
-fn parse_ending(s: &Vec<String>, schema: &Schema) -> Option<Ending> {
-    let length = s.len();
-    if length < 3 {
-        return None;
+/// Whether every cased letter in the word is upper-case, e.g. `ВЕЛИКИЙ` but not `Великий`.
+/// A word with fewer than two cased letters (a lone capital like a sentence-initial `Ю`,
+/// numbers, punctuation) is not classified as all-uppercase — a single letter's case is
+/// genuinely ambiguous, so it's left to [`CasingMode::TitleFirst`].
+fn is_all_uppercase(chars: &[char]) -> bool {
+    let mut cased = 0;
+    for c in chars {
+        if c.is_lowercase() {
+            return false;
+        }
+        if c.is_uppercase() {
+            cased += 1;
+        }
     }
-
-    match schema.get_ending(&s[length - 1..].concat()) {
-        Some(matched) => return Some(Ending {
-            translate: propagate_case_from_source(matched, &s[length - 1..].concat(), false),
-            ending_start: length - 1,
-        }),
-        None => ()
-    };
-    return match schema.get_ending(&s[length - 2..].concat()) {
-        Some(matched) => return Some(Ending {
-            translate: propagate_case_from_source(matched, &s[length - 2..].concat(), false),
-            ending_start: length - 2,
-        }),
-        None => None
-    };
+    cased > 1
 }
 
-struct Ending {
-    translate: String,
-    ending_start: usize,
+/// Match the word's suffix against `ending_mapping`, preferring the shortest
+/// matching key (e.g. a 1-char ending wins over an overlapping 2-char one) while
+/// no longer being limited to a 2-letter ending.
+fn parse_ending(chars: &[char], lower: &[char], schema: &Schema, mode: CasingMode) -> Option<(usize, String)> {
+    let reversed: Vec<char> = lower.iter().rev().cloned().collect();
+    let (matched, length) = schema.automaton.ending.shortest_match(&reversed, 0)?;
+    if length >= chars.len() {
+        return None;
+    }
+
+    let ending_start = chars.len() - length;
+    let source: String = chars[ending_start..].iter().collect();
+    Some((ending_start, propagate_case_from_source(matched.to_string(), &source, mode)))
 }
 
-/// Find letter transliteration with steps priority(apply higher):
-/// 1. prefix parse
-/// 2. postfix parse
-/// 3. letter parse
+/// Find a letter's transliteration with steps priority (apply higher):
+/// 1. prev-context parse: longest `prev_mapping` key ending at this letter
+/// 2. next-context parse: longest `next_mapping` key starting at this letter
+/// 3. letter parse: `mapping` entry for this letter alone
 /// 4. use input letter
-fn parse_letter(letter_with_neighbors: &[String], schema: &Schema) -> String {
-    let prefix: String = letter_with_neighbors[..2].concat();
-    let postfix: String = letter_with_neighbors[1..].concat();
-    let letter: String = letter_with_neighbors[1..2].concat();
+fn parse_letter(chars: &[char], lower: &[char], cursor: usize, schema: &Schema, mode: CasingMode) -> String {
+    let letter = chars[cursor].to_string();
     let mut result = letter.clone();
-    match schema.get_letter(&letter) {
-        Some(matched) => result = matched,
-        None => ()
-    };
-    match schema.get_next(&postfix) {
-        Some(matched) => result = matched,
-        None => ()
-    };
-    match schema.get_pref(&prefix) {
-        Some(matched) => result = matched,
-        None => ()
-    };
-    propagate_case_from_source(result, &letter, true)
+
+    if let Some((matched, _)) = schema.automaton.forward.longest_match(lower, cursor) {
+        result = matched.to_string();
+    }
+
+    // A `next_mapping` key of just the current letter only means anything at the
+    // very end of a word (no real next letter); elsewhere it's not a match.
+    match schema.automaton.next.longest_match(lower, cursor) {
+        Some((matched, length)) if length > 1 || cursor + 1 == lower.len() => result = matched.to_string(),
+        _ => ()
+    }
+
+    // A `prev_mapping` key of just the current letter only means anything at the
+    // very start of a word (no real previous letter); elsewhere it's not a match.
+    let backward_context: Vec<char> = lower[..=cursor].iter().rev().cloned().collect();
+    match schema.automaton.backward.longest_match(&backward_context, 0) {
+        Some((matched, length)) if cursor == 0 || length > 1 => result = matched.to_string(),
+        _ => ()
+    }
+
+    propagate_case_from_source(result, &letter, mode)
 }
 
-fn propagate_case_from_source(result: String, source_letter: &str, only_first_symbol: bool) -> String {
+/// Apply `source_letter`'s case to `result` according to `mode`. `mode` must already be
+/// resolved to [`CasingMode::AsSource`] or [`CasingMode::TitleFirst`]; [`CasingMode::Auto`]
+/// falls back to `TitleFirst` since it should have been resolved by [`parse_word_by_schema`].
+fn propagate_case_from_source(result: String, source_letter: &str, mode: CasingMode) -> String {
     // Determinate case of letter
     let letter_upper = source_letter.chars().any(|letter| letter.is_uppercase());
 
@@ -201,21 +488,22 @@ fn propagate_case_from_source(result: String, source_letter: &str, only_first_sy
         return result.to_owned();
     }
 
-    if only_first_symbol {
-        let mut c = result.chars();
-        match c.next() {
-            None => String::new(),
-            Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+    match mode {
+        CasingMode::AsSource => result.to_uppercase(),
+        CasingMode::TitleFirst | CasingMode::Auto => {
+            let mut c = result.chars();
+            match c.next() {
+                None => String::new(),
+                Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+            }
         }
-    } else {
-        result.to_uppercase()
     }
 }
 
 
 #[cfg(test)]
 mod tests {
-    use crate::{Schema, parse_by_schema};
+    use crate::{Schema, Reversibility, parse_by_schema, detransliterate_by_schema};
 
     #[test]
     fn schema_test() {
@@ -318,4 +606,31 @@ mod tests {
         //Then
         assert_eq!(transliterated_words, expected_words)
     }
+
+    #[test]
+    fn detransliterate_round_trip() {
+        //Given
+        let test_words = vec!["б", "пол"];
+        let schema = Schema::for_name("wikipedia");
+
+        //When
+        let round_tripped: Vec<String> = test_words.iter()
+            .map(|word| detransliterate_by_schema(&parse_by_schema(word, &schema), &schema))
+            .collect();
+
+        //Then
+        assert_eq!(round_tripped, test_words)
+    }
+
+    #[test]
+    fn wikipedia_schema_is_best_effort() {
+        //Given
+        let schema = Schema::for_name("wikipedia");
+
+        //When
+        let reversibility = schema.reversibility();
+
+        //Then
+        assert_eq!(reversibility, Reversibility::BestEffort)
+    }
 }
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+/// A map from sequences of `char`s to transliteration rules, supporting
+/// longest-prefix-match lookups so a schema's contextual rules are no
+/// longer limited to a single neighboring letter.
+#[derive(Debug, Default)]
+pub(crate) struct Trie {
+    root: TrieNode,
+}
+
+#[derive(Debug, Default)]
+struct TrieNode {
+    children: HashMap<char, TrieNode>,
+    value: Option<String>,
+}
+
+impl Trie {
+    pub(crate) fn new() -> Trie {
+        Trie::default()
+    }
+
+    pub(crate) fn insert(&mut self, key: &str, value: String) {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.entry(c).or_insert_with(TrieNode::default);
+        }
+        node.value = Some(value);
+    }
+
+    /// Find the longest key that matches a prefix of `chars[start..]`,
+    /// returning its replacement and the number of `char`s it matched.
+    pub(crate) fn longest_match(&self, chars: &[char], start: usize) -> Option<(&str, usize)> {
+        let mut node = &self.root;
+        let mut best: Option<(&str, usize)> = None;
+
+        for (offset, c) in chars[start..].iter().enumerate() {
+            node = match node.children.get(c) {
+                Some(next) => next,
+                None => break,
+            };
+            if let Some(value) = &node.value {
+                best = Some((value.as_str(), offset + 1));
+            }
+        }
+
+        best
+    }
+
+    /// Find the shortest key that matches a prefix of `chars[start..]`,
+    /// returning its replacement and the number of `char`s it matched.
+    pub(crate) fn shortest_match(&self, chars: &[char], start: usize) -> Option<(&str, usize)> {
+        let mut node = &self.root;
+
+        for (offset, c) in chars[start..].iter().enumerate() {
+            node = match node.children.get(c) {
+                Some(next) => next,
+                None => break,
+            };
+            if let Some(value) = &node.value {
+                return Some((value.as_str(), offset + 1));
+            }
+        }
+
+        None
+    }
+}
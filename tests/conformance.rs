@@ -0,0 +1,38 @@
+extern crate iuliia_rust;
+
+use iuliia_rust::{parse_by_schema, Schema};
+
+/// Every shipped schema carries its own reference `samples`; walk all of them
+/// and make sure transliterating the source reproduces the expected output,
+/// so a regression in any rule set fails loudly instead of only the
+/// hand-picked `wikipedia` cases in `src/lib.rs`.
+#[test]
+fn every_schema_matches_its_own_samples() {
+    let mut checked = 0;
+
+    for name in Schema::available() {
+        let schema = Schema::for_name(name);
+        let samples = match schema.samples() {
+            Some(samples) => samples,
+            None => continue,
+        };
+
+        for sample in samples {
+            if sample.len() < 2 {
+                continue;
+            }
+            let source = &sample[0];
+            let expected = &sample[1];
+            let actual = parse_by_schema(source, &schema);
+
+            assert_eq!(
+                &actual, expected,
+                "schema `{}` mistransliterated sample `{}`: expected `{}`, got `{}`",
+                name, source, expected, actual
+            );
+            checked += 1;
+        }
+    }
+
+    assert!(checked > 0, "no schema samples were found to check");
+}